@@ -1,9 +1,18 @@
 use std::{
     ffi::{c_char, c_int, c_void, CStr},
+    fmt,
+    num::NonZeroU32,
+    ops::BitXor,
     ptr::null,
     time::{Duration, Instant},
 };
 
+#[cfg(feature = "dynamic")]
+use std::path::Path;
+
+type TerminateCb = Option<extern "C" fn(state: *const c_void) -> c_int>;
+
+#[cfg(feature = "static-link")]
 #[link(name = "ipamir")]
 extern "C" {
     fn ipamir_signature() -> *const c_char;
@@ -15,12 +24,205 @@ extern "C" {
     fn ipamir_solve(solver: *const c_void) -> c_int;
     fn ipamir_val_obj(solver: *const c_void) -> u64;
     fn ipamir_val_lit(solver: *const c_void, lit: i32) -> i32;
-    fn ipamir_set_terminate(
-        solver: *const c_void,
-        state: *const c_void,
-        x: Option<extern "C" fn(state: *const c_void) -> c_int>,
-    );
+    fn ipamir_set_terminate(solver: *const c_void, state: *const c_void, x: TerminateCb);
+
+}
+
+/// The resolved `ipamir_*` entry points a solver routes its calls through.
+///
+/// For the statically linked backend these point at the symbols in the
+/// `#[link]`ed library; with the `dynamic` feature they are resolved from a
+/// shared object opened at runtime, and `_lib` keeps that library loaded for
+/// as long as the solver lives.
+struct Symbols {
+    #[cfg(feature = "dynamic")]
+    _lib: Option<libloading::Library>,
+    signature: unsafe extern "C" fn() -> *const c_char,
+    #[cfg(any(feature = "static-link", feature = "dynamic"))]
+    init: unsafe extern "C" fn() -> *const c_void,
+    release: unsafe extern "C" fn(*const c_void),
+    add_hard: unsafe extern "C" fn(*const c_void, i32),
+    add_soft_lit: unsafe extern "C" fn(*const c_void, i32, u64),
+    assume: unsafe extern "C" fn(*const c_void, i32),
+    solve: unsafe extern "C" fn(*const c_void) -> c_int,
+    val_obj: unsafe extern "C" fn(*const c_void) -> u64,
+    val_lit: unsafe extern "C" fn(*const c_void, i32) -> i32,
+    set_terminate: unsafe extern "C" fn(*const c_void, *const c_void, TerminateCb),
+}
+
+/// Error returned by [`IPAMIR::load_from_path`] when a backend cannot be opened.
+#[cfg(feature = "dynamic")]
+#[derive(Debug)]
+pub struct LoadError(libloading::Error);
+
+#[cfg(feature = "dynamic")]
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not load IPAMIR backend: {}", self.0)
+    }
+}
+
+#[cfg(feature = "dynamic")]
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[cfg(feature = "dynamic")]
+impl From<libloading::Error> for LoadError {
+    fn from(e: libloading::Error) -> Self {
+        LoadError(e)
+    }
+}
+
+/// A CNF variable, stored 1-based as the IPAMIR/DIMACS convention requires.
+///
+/// The `NonZeroU32` backing makes the clause terminator value `0` unrepresentable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Var(NonZeroU32);
+
+impl Var {
+    /// The largest representable variable index. Literals cross the FFI boundary
+    /// as `i32`, so a variable index must fit in the positive `i32` range for
+    /// both of its literals to be representable.
+    pub const MAX_INDEX: u32 = i32::MAX as u32;
+
+    /// Create a variable from its 1-based index, returning `None` for `0` or for
+    /// an index past [`Var::MAX_INDEX`] (whose literals would not fit in `i32`).
+    pub fn new(idx: u32) -> Option<Var> {
+        if idx > Var::MAX_INDEX {
+            return None;
+        }
+        NonZeroU32::new(idx).map(Var)
+    }
+
+    /// The 1-based index of this variable.
+    pub fn index(self) -> u32 {
+        self.0.get()
+    }
+}
+
+/// The sign (polarity) of a literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sign {
+    Pos,
+    Neg,
+}
+
+/// A literal: a variable together with a sign, encoded as a non-zero `i32`
+/// exactly as IPAMIR expects it on the FFI boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lit(i32);
+
+impl Lit {
+    /// The positive literal of `var`.
+    pub fn positive(var: Var) -> Lit {
+        Lit(var.index() as i32)
+    }
+
+    /// The negative literal of `var`.
+    pub fn negative(var: Var) -> Lit {
+        Lit(-(var.index() as i32))
+    }
+
+    /// Build a literal from a raw FFI value, rejecting the terminator `0` and
+    /// any magnitude past [`Var::MAX_INDEX`] (whose variable would not satisfy
+    /// the [`Var`] invariant).
+    pub fn from_raw(val: i32) -> Result<Lit, InvalidLitVal> {
+        if val == 0 || val.unsigned_abs() > Var::MAX_INDEX {
+            Err(InvalidLitVal)
+        } else {
+            Ok(Lit(val))
+        }
+    }
+
+    /// The variable this literal refers to.
+    pub fn var(self) -> Var {
+        Var(NonZeroU32::new(self.0.unsigned_abs()).unwrap())
+    }
+
+    /// The sign of this literal.
+    pub fn sign(self) -> Sign {
+        if self.0 > 0 {
+            Sign::Pos
+        } else {
+            Sign::Neg
+        }
+    }
+
+    /// The raw non-zero `i32` passed across the FFI boundary.
+    pub fn to_raw(self) -> i32 {
+        self.0
+    }
+}
+
+impl BitXor<Sign> for Var {
+    type Output = Lit;
+
+    fn bitxor(self, sign: Sign) -> Lit {
+        match sign {
+            Sign::Pos => Lit::positive(self),
+            Sign::Neg => Lit::negative(self),
+        }
+    }
+}
+
+/// Error returned when trying to construct a [`Lit`] from the reserved value `0`
+/// or from a value whose magnitude is past [`Var::MAX_INDEX`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidLitVal;
+
+impl fmt::Display for InvalidLitVal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid literal value: 0 or magnitude past Var::MAX_INDEX")
+    }
+}
+
+impl std::error::Error for InvalidLitVal {}
+
+/// The value a literal takes in an optimal assignment.
+///
+/// IPAMIR's `val` returns `lit` when the literal is true, `-lit` when it is
+/// false, and `0` when the optimum does not determine it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LitValue {
+    True,
+    False,
+    DontCare,
+}
+
+impl LitValue {
+    /// Decode the raw `val_lit` return for the queried literal.
+    fn from_raw(queried: Lit, val: i32) -> LitValue {
+        if val == 0 {
+            LitValue::DontCare
+        } else if val == queried.to_raw() {
+            LitValue::True
+        } else {
+            LitValue::False
+        }
+    }
 
+    /// Interpret the value as a boolean, treating [`LitValue::DontCare`] as the
+    /// given default polarity.
+    pub fn to_bool_with_polarity(self, default: bool) -> bool {
+        match self {
+            LitValue::True => true,
+            LitValue::False => false,
+            LitValue::DontCare => default,
+        }
+    }
+}
+
+/// Signal returned by a terminate callback to control an in-progress solve.
+///
+/// Mapped to the IPAMIR C return codes: [`SolveControl::Continue`] is `0`,
+/// [`SolveControl::Stop`] is `1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SolveControl {
+    Continue,
+    Stop,
 }
 
 pub struct Solution<'a> {
@@ -36,79 +238,198 @@ pub enum MaxSatResult<'a> {
 
 pub struct IPAMIR {
     ptr: *const c_void,
+    sym: Symbols,
+    terminate: Option<Box<Box<dyn FnMut() -> SolveControl + Send>>>,
+    softs: Vec<(Lit, u64)>,
+}
+
+#[cfg(feature = "static-link")]
+impl Default for IPAMIR {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl IPAMIR {
+    /// Create a solver backed by the statically linked `ipamir` library.
+    #[cfg(feature = "static-link")]
     pub fn new() -> Self {
-        let ptr = unsafe { ipamir_init() };
-        assert!(ptr != null());
-        IPAMIR { ptr }
+        let sym = Symbols {
+            #[cfg(feature = "dynamic")]
+            _lib: None,
+            signature: ipamir_signature,
+            init: ipamir_init,
+            release: ipamir_release,
+            add_hard: ipamir_add_hard,
+            add_soft_lit: ipamir_add_soft_lit,
+            assume: ipamir_assume,
+            solve: ipamir_solve,
+            val_obj: ipamir_val_obj,
+            val_lit: ipamir_val_lit,
+            set_terminate: ipamir_set_terminate,
+        };
+        Self::with_symbols(sym)
+    }
+
+    /// Open an IPAMIR shared object at runtime, resolve its `ipamir_*`
+    /// symbols, and initialize a solver backed by them. The library is kept
+    /// loaded for the lifetime of the returned solver.
+    #[cfg(feature = "dynamic")]
+    pub fn load_from_path(path: &Path) -> Result<Self, LoadError> {
+        unsafe {
+            let lib = libloading::Library::new(path)?;
+            // Copy each resolved symbol out of its `libloading::Symbol` into a
+            // plain function pointer; `_lib` below keeps them valid.
+            let sym = Symbols {
+                signature: *lib.get(b"ipamir_signature\0")?,
+                init: *lib.get(b"ipamir_init\0")?,
+                release: *lib.get(b"ipamir_release\0")?,
+                add_hard: *lib.get(b"ipamir_add_hard\0")?,
+                add_soft_lit: *lib.get(b"ipamir_add_soft_lit\0")?,
+                assume: *lib.get(b"ipamir_assume\0")?,
+                solve: *lib.get(b"ipamir_solve\0")?,
+                val_obj: *lib.get(b"ipamir_val_obj\0")?,
+                val_lit: *lib.get(b"ipamir_val_lit\0")?,
+                set_terminate: *lib.get(b"ipamir_set_terminate\0")?,
+                _lib: Some(lib),
+            };
+            Ok(Self::with_symbols(sym))
+        }
+    }
+
+    #[cfg(any(feature = "static-link", feature = "dynamic"))]
+    fn with_symbols(sym: Symbols) -> Self {
+        let ptr = unsafe { (sym.init)() };
+        assert!(!ptr.is_null());
+        IPAMIR {
+            ptr,
+            sym,
+            terminate: None,
+            softs: Vec::new(),
+        }
+    }
+
+    /// Register a callback queried periodically during [`IPAMIR::solve`]; it
+    /// returns [`SolveControl::Stop`] to abort the search. The callback stays
+    /// installed across solve calls until replaced or [`IPAMIR::clear_terminate`]
+    /// is called.
+    ///
+    /// It composes with the `timeout` argument of [`IPAMIR::solve`]: the solve
+    /// stops as soon as either the deadline passes or the callback asks to stop,
+    /// so a user interrupt flag (e.g. an `AtomicBool` set on Ctrl-C) and a
+    /// timeout can be used together.
+    pub fn set_terminate(&mut self, callback: impl FnMut() -> SolveControl + Send + 'static) {
+        self.terminate = Some(Box::new(Box::new(callback)));
+    }
+
+    /// Remove a callback previously installed with [`IPAMIR::set_terminate`].
+    pub fn clear_terminate(&mut self) {
+        self.terminate = None;
     }
 
     pub fn signature(&self) -> &str {
-        let c_buf: *const c_char = unsafe { ipamir_signature() };
+        let c_buf: *const c_char = unsafe { (self.sym.signature)() };
         let c_str: &CStr = unsafe { CStr::from_ptr(c_buf) };
         let str_slice: &str = c_str.to_str().unwrap();
         str_slice
     }
 
-    pub fn add_soft_lit(&mut self, lit: i32, weight: u64) {
-        unsafe { ipamir_add_soft_lit(self.ptr, lit, weight) };
+    pub fn add_soft_lit(&mut self, lit: Lit, weight: u64) {
+        self.add_soft_lit_raw(lit.to_raw(), weight);
+    }
+
+    /// Escape hatch for callers holding a raw IPAMIR literal value.
+    pub fn add_soft_lit_raw(&mut self, lit: i32, weight: u64) {
+        unsafe { (self.sym.add_soft_lit)(self.ptr, lit, weight) };
+        if let Ok(lit) = Lit::from_raw(lit) {
+            self.softs.push((lit, weight));
+        }
+    }
+
+    pub fn add_clause(&mut self, lits: impl Iterator<Item = Lit>) {
+        self.add_clause_raw(lits.map(Lit::to_raw));
     }
 
-    pub fn add_clause(&mut self, lits: impl Iterator<Item = i32>) {
+    /// Escape hatch for callers holding raw IPAMIR literal values.
+    pub fn add_clause_raw(&mut self, lits: impl Iterator<Item = i32>) {
         for lit in lits {
-            unsafe { ipamir_add_hard(self.ptr, lit) };
+            unsafe { (self.sym.add_hard)(self.ptr, lit) };
         }
-        unsafe { ipamir_add_hard(self.ptr, 0) };
+        unsafe { (self.sym.add_hard)(self.ptr, 0) };
+    }
+
+    /// Assume `lit` to be true for the next [`IPAMIR::solve`] call. Assumptions
+    /// hold for a single solve and are cleared by the backend afterwards, so
+    /// this is equivalent to passing the literal through `solve`'s iterator.
+    pub fn assume(&mut self, lit: Lit) {
+        self.assume_raw(lit.to_raw());
+    }
+
+    /// Escape hatch for callers holding a raw IPAMIR literal value.
+    pub fn assume_raw(&mut self, lit: i32) {
+        unsafe { (self.sym.assume)(self.ptr, lit) };
     }
 
     pub fn solve(
         &mut self,
         timeout: Option<Duration>,
-        assumptions: impl Iterator<Item = i32>,
-    ) -> MaxSatResult {
+        assumptions: impl Iterator<Item = Lit>,
+    ) -> MaxSatResult<'_> {
+        let ptr = self.ptr;
+        let assume_fn = self.sym.assume;
+        let solve_fn = self.sym.solve;
+        let set_terminate_fn = self.sym.set_terminate;
         for lit in assumptions {
-            unsafe { ipamir_assume(self.ptr, lit) };
+            unsafe { assume_fn(ptr, lit.to_raw()) };
         }
 
-        struct CallbackUserData {
-            start_time: Instant,
-            timeout: Duration,
-        }
-        let mut userdata: Option<CallbackUserData> = None;
+        let deadline = timeout.map(|t| (Instant::now(), t));
+        let install = deadline.is_some() || self.terminate.is_some();
 
-        if let Some(timeout) = timeout {
-            userdata = Some(CallbackUserData {
-                start_time: Instant::now(),
-                timeout,
+        // Compose the optional timeout with any registered user callback. The
+        // inner `dyn FnMut` is a fat pointer, so we wrap it in a second box and
+        // hand its (thin) address to IPAMIR as the opaque state pointer; the
+        // address stays valid while `state` lives even though the outer box is
+        // moved below. The boxed state is dropped, and the callback nulled,
+        // before this method returns.
+        let mut state: Option<Box<Box<dyn FnMut() -> SolveControl + Send>>> = None;
+        if install {
+            let mut user = self.terminate.as_deref_mut();
+            let composed: Box<dyn FnMut() -> SolveControl + Send> = Box::new(move || {
+                if let Some((start, t)) = deadline {
+                    if start.elapsed() > t {
+                        return SolveControl::Stop;
+                    }
+                }
+                if let Some(f) = &mut user {
+                    if let SolveControl::Stop = f() {
+                        return SolveControl::Stop;
+                    }
+                }
+                SolveControl::Continue
             });
+            let mut boxed = Box::new(composed);
+            let state_ptr =
+                &mut *boxed as *mut Box<dyn FnMut() -> SolveControl + Send> as *const c_void;
 
-            extern "C" fn cb(state: *const c_void) -> c_int {
-                let ptr = state as *const CallbackUserData;
-                let user_data = unsafe { &*ptr };
-
-                if user_data.start_time.elapsed() > user_data.timeout {
-                    1
-                } else {
-                    0
+            extern "C" fn trampoline(state: *const c_void) -> c_int {
+                let cb = unsafe { &mut *(state as *mut Box<dyn FnMut() -> SolveControl + Send>) };
+                match cb() {
+                    SolveControl::Continue => 0,
+                    SolveControl::Stop => 1,
                 }
             }
 
-            unsafe {
-                ipamir_set_terminate(
-                    self.ptr,
-                    userdata.as_ref().unwrap() as *const CallbackUserData as *const c_void,
-                    Some(cb),
-                )
-            }
+            unsafe { set_terminate_fn(ptr, state_ptr, Some(trampoline)) };
+            state = Some(boxed);
         }
 
-        let code = unsafe { ipamir_solve(self.ptr) };
+        let code = unsafe { solve_fn(ptr) };
 
-        if userdata.is_some() {
-            unsafe { ipamir_set_terminate(self.ptr, null(), None) };
+        if state.is_some() {
+            unsafe { set_terminate_fn(ptr, null(), None) };
         }
+        drop(state);
 
         if code == 0 {
             MaxSatResult::Timeout(None)
@@ -126,19 +447,183 @@ impl IPAMIR {
     }
 }
 
+// SAFETY: each `IPAMIR` owns its solver instance exclusively — the wrapped
+// `*const c_void` is never shared between objects and there is no global
+// mutable C state touched across instances — so an owned solver can be moved
+// to another thread (e.g. `std::thread::spawn(move || solver.solve(..))` or a
+// rayon/crossbeam worker for portfolio-style parallel optimization). The other
+// fields are the table of function pointers, the recorded soft literals (plain
+// `Send` data), and the boxed terminate callback, whose `FnMut` is bound
+// `Send` at `set_terminate`, so moving it to another thread is sound. We do
+// *not* implement `Sync`: the C solver is not safe to call concurrently
+// through a shared reference.
+unsafe impl Send for IPAMIR {}
+
 impl Drop for IPAMIR {
     fn drop(&mut self) {
-        unsafe { ipamir_release(self.ptr) };
+        unsafe { (self.sym.release)(self.ptr) };
     }
 }
 
 impl<'a> Solution<'a> {
     pub fn get_objective_value(&self) -> u64 {
-        unsafe { ipamir_val_obj(self.ipamir.ptr) }
+        unsafe { (self.ipamir.sym.val_obj)(self.ipamir.ptr) }
+    }
+
+    pub fn get_literal_value(&self, lit: Lit) -> LitValue {
+        LitValue::from_raw(lit, self.get_literal_value_raw(lit))
+    }
+
+    /// Escape hatch returning the undecoded IPAMIR `val_lit` result.
+    pub fn get_literal_value_raw(&self, lit: Lit) -> i32 {
+        unsafe { (self.ipamir.sym.val_lit)(self.ipamir.ptr, lit.to_raw()) }
+    }
+
+    /// Materialize the assignment of the given variables in one call, in the
+    /// order they are yielded.
+    pub fn model(&self, vars: impl Iterator<Item = Var>) -> Vec<LitValue> {
+        vars.map(|v| self.get_literal_value(Lit::positive(v)))
+            .collect()
+    }
+
+    /// The soft literals that are falsified in this solution, paired with the
+    /// weight they contribute to the objective. The pairs are reported in the
+    /// order the soft constraints were added.
+    pub fn falsified_softs(&self) -> impl Iterator<Item = (Lit, u64)> + '_ {
+        self.ipamir
+            .softs
+            .iter()
+            .copied()
+            .filter(move |&(lit, _)| matches!(self.get_literal_value(lit), LitValue::False))
+    }
+}
+
+#[cfg(feature = "rustsat")]
+impl Lit {
+    /// Translate a `rustsat` literal (0-based variable index plus sign) into the
+    /// 1-based IPAMIR convention used by this crate.
+    pub fn from_rustsat(lit: rustsat::types::Lit) -> Lit {
+        // `to_ipasir` already yields the signed 1-based DIMACS value IPAMIR
+        // expects, and is never `0` for a valid variable index.
+        Lit::from_raw(lit.to_ipasir()).expect("rustsat literal maps to the reserved value 0")
+    }
+}
+
+#[cfg(feature = "rustsat")]
+impl IPAMIR {
+    /// Add every clause of a `rustsat` CNF formula as a hard constraint.
+    pub fn add_cnf(&mut self, cnf: &rustsat::instances::Cnf) {
+        for clause in cnf.iter() {
+            self.add_clause(clause.iter().map(|&l| Lit::from_rustsat(l)));
+        }
+    }
+
+    /// Register weighted soft literals from a `rustsat` objective expressed as
+    /// `(literal, weight)` pairs.
+    pub fn add_objective(
+        &mut self,
+        softs: impl IntoIterator<Item = (rustsat::types::Lit, u64)>,
+    ) {
+        for (lit, weight) in softs {
+            self.add_soft_lit(Lit::from_rustsat(lit), weight);
+        }
+    }
+
+    /// Map an IPAMIR solve outcome onto the tri-state [`SolverResult`] that
+    /// `rustsat` callers expect: the optimum is reported as satisfiable, an
+    /// exhausted timeout as interrupted.
+    ///
+    /// [`SolverResult`]: rustsat::solvers::SolverResult
+    fn map_result(result: MaxSatResult) -> anyhow::Result<rustsat::solvers::SolverResult> {
+        use rustsat::solvers::SolverResult;
+        Ok(match result {
+            MaxSatResult::Optimal(_) => SolverResult::Sat,
+            MaxSatResult::Timeout(_) => SolverResult::Interrupted,
+            MaxSatResult::Unsat => SolverResult::Unsat,
+            MaxSatResult::Error => anyhow::bail!("the IPAMIR backend reported an error"),
+        })
+    }
+}
+
+/// Hard clauses can be streamed into the solver with [`Extend`], so a
+/// `rustsat::instances::Cnf` drops straight in via `solver.extend(&cnf)`.
+#[cfg(feature = "rustsat")]
+impl Extend<rustsat::types::Clause> for IPAMIR {
+    fn extend<T: IntoIterator<Item = rustsat::types::Clause>>(&mut self, iter: T) {
+        for clause in iter {
+            self.add_clause(clause.iter().map(|&l| Lit::from_rustsat(l)));
+        }
+    }
+}
+
+#[cfg(feature = "rustsat")]
+impl<'a> Extend<&'a rustsat::types::Clause> for IPAMIR {
+    fn extend<T: IntoIterator<Item = &'a rustsat::types::Clause>>(&mut self, iter: T) {
+        for clause in iter {
+            self.add_clause(clause.iter().map(|&l| Lit::from_rustsat(l)));
+        }
+    }
+}
+
+/// Expose the solver as a `rustsat` SAT backend. Only the hard clauses take
+/// part in satisfiability; soft literals added through [`IPAMIR::add_objective`]
+/// steer which model is returned when several satisfy the hard part.
+#[cfg(feature = "rustsat")]
+impl rustsat::solvers::Solve for IPAMIR {
+    fn signature(&self) -> &'static str {
+        let c_buf: *const c_char = unsafe { (self.sym.signature)() };
+        // The signature lives in static storage inside the backend library, so
+        // the borrow outlives any individual call.
+        let c_str: &'static CStr = unsafe { CStr::from_ptr(c_buf) };
+        c_str.to_str().unwrap()
+    }
+
+    fn solve(&mut self) -> anyhow::Result<rustsat::solvers::SolverResult> {
+        IPAMIR::map_result(self.solve(None, std::iter::empty()))
+    }
+
+    fn lit_val(&self, lit: rustsat::types::Lit) -> anyhow::Result<rustsat::types::TernaryVal> {
+        use rustsat::types::TernaryVal;
+        let lit = Lit::from_rustsat(lit);
+        let raw = unsafe { (self.sym.val_lit)(self.ptr, lit.to_raw()) };
+        Ok(match LitValue::from_raw(lit, raw) {
+            LitValue::True => TernaryVal::True,
+            LitValue::False => TernaryVal::False,
+            LitValue::DontCare => TernaryVal::DontCare,
+        })
+    }
+
+    fn add_clause_ref<C>(&mut self, clause: &C) -> anyhow::Result<()>
+    where
+        C: AsRef<rustsat::types::Cl> + ?Sized,
+    {
+        self.add_clause(clause.as_ref().iter().map(|&l| Lit::from_rustsat(l)));
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rustsat")]
+impl rustsat::solvers::SolveIncremental for IPAMIR {
+    fn solve_assumps(
+        &mut self,
+        assumps: &[rustsat::types::Lit],
+    ) -> anyhow::Result<rustsat::solvers::SolverResult> {
+        let assumptions: Vec<Lit> = assumps.iter().map(|&l| Lit::from_rustsat(l)).collect();
+        IPAMIR::map_result(self.solve(None, assumptions.into_iter()))
     }
 
-    pub fn get_literal_value(&self, lit: i32) -> i32 {
-        unsafe { ipamir_val_lit(self.ipamir.ptr, lit) }
+    fn core(&mut self) -> anyhow::Result<Vec<rustsat::types::Lit>> {
+        anyhow::bail!("the IPAMIR interface does not expose unsatisfiable cores")
+    }
+}
+
+#[cfg(feature = "rustsat")]
+impl<'a> Solution<'a> {
+    /// Query the optimal value of a `rustsat` variable in the returned model,
+    /// or `None` if its 1-based index does not fit within [`Var::MAX_INDEX`].
+    pub fn rustsat_value(&self, var: rustsat::types::Var) -> Option<LitValue> {
+        let v = Var::new(var.idx32().checked_add(1)?)?;
+        Some(self.get_literal_value(Lit::positive(v)))
     }
 }
 
@@ -146,9 +631,73 @@ impl<'a> Solution<'a> {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "static-link")]
     #[test]
     fn it_works() {
         let s = IPAMIR::new();
         println!("{}", s.signature());
     }
+
+    #[test]
+    fn var_new_bounds() {
+        assert!(Var::new(0).is_none());
+        assert_eq!(Var::new(1).unwrap().index(), 1);
+        assert_eq!(Var::new(Var::MAX_INDEX).unwrap().index(), Var::MAX_INDEX);
+        assert!(Var::new(Var::MAX_INDEX + 1).is_none());
+        assert!(Var::new(u32::MAX).is_none());
+    }
+
+    #[test]
+    fn from_raw_rejects_zero_and_out_of_range() {
+        assert_eq!(Lit::from_raw(0), Err(InvalidLitVal));
+        assert_eq!(Lit::from_raw(i32::MIN), Err(InvalidLitVal));
+        assert_eq!(Lit::from_raw(3).unwrap().to_raw(), 3);
+        assert_eq!(Lit::from_raw(-3).unwrap().to_raw(), -3);
+        assert_eq!(Lit::from_raw(i32::MAX).unwrap().to_raw(), i32::MAX);
+    }
+
+    #[test]
+    fn lit_roundtrips_through_var_and_sign() {
+        let v = Var::new(7).unwrap();
+        let pos = Lit::positive(v);
+        let neg = Lit::negative(v);
+        assert_eq!(pos.to_raw(), 7);
+        assert_eq!(neg.to_raw(), -7);
+        assert_eq!(pos.var(), v);
+        assert_eq!(neg.var(), v);
+        assert_eq!(pos.sign(), Sign::Pos);
+        assert_eq!(neg.sign(), Sign::Neg);
+    }
+
+    #[test]
+    fn var_xor_sign_matches_constructors() {
+        let v = Var::new(4).unwrap();
+        assert_eq!(v ^ Sign::Pos, Lit::positive(v));
+        assert_eq!(v ^ Sign::Neg, Lit::negative(v));
+    }
+
+    #[test]
+    fn lit_value_decodes_raw_val() {
+        let lit = Lit::from_raw(5).unwrap();
+        assert_eq!(LitValue::from_raw(lit, 5), LitValue::True);
+        assert_eq!(LitValue::from_raw(lit, -5), LitValue::False);
+        assert_eq!(LitValue::from_raw(lit, 0), LitValue::DontCare);
+    }
+
+    #[test]
+    fn lit_value_to_bool_with_polarity() {
+        assert!(LitValue::True.to_bool_with_polarity(false));
+        assert!(!LitValue::False.to_bool_with_polarity(true));
+        assert!(LitValue::DontCare.to_bool_with_polarity(true));
+        assert!(!LitValue::DontCare.to_bool_with_polarity(false));
+    }
+
+    #[cfg(feature = "rustsat")]
+    #[test]
+    fn from_rustsat_translates_index_and_sign() {
+        // rustsat is 0-based, this crate is 1-based.
+        assert_eq!(Lit::from_rustsat(rustsat::types::Lit::positive(0)).to_raw(), 1);
+        assert_eq!(Lit::from_rustsat(rustsat::types::Lit::negative(0)).to_raw(), -1);
+        assert_eq!(Lit::from_rustsat(rustsat::types::Lit::positive(41)).to_raw(), 42);
+    }
 }